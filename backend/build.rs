@@ -0,0 +1,39 @@
+//! 🏗️ BUILD SCRIPT EMBEDDING BUILD METADATA
+//!
+//! Exposes `GIT_SHA`, `BUILD_TIME` and `RUSTC_VERSION` to the crate through `env!`,
+//! so the `/version` endpoint can report exactly which build is deployed.
+
+use std::process::Command;
+use std::time::SystemTime;
+
+fn main() {
+    let git_sha = command_output("git", &["rev-parse", "--short", "HEAD"]);
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"]);
+    let build_time = httpdate::fmt_http_date(SystemTime::now());
+
+    println!("cargo:rustc-env=GIT_SHA={}", git_sha);
+    println!("cargo:rustc-env=BUILD_TIME={}", build_time);
+    println!("cargo:rustc-env=RUSTC_VERSION={}", rustc_version);
+
+    // Re-run when the checked-out commit changes so the SHA stays accurate.
+    let git_dir = command_output("git", &["rev-parse", "--git-dir"]);
+    if git_dir != "unknown" {
+        println!("cargo:rerun-if-changed={}/HEAD", git_dir);
+        println!("cargo:rerun-if-changed={}/refs/heads", git_dir);
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+/// Runs a command and returns its trimmed stdout, or `"unknown"` if it fails.
+fn command_output(program: &str, args: &[&str]) -> String {
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|stdout| stdout.trim().to_string())
+        .filter(|stdout| !stdout.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
@@ -1 +1,3 @@
+pub mod shared;
+#[cfg(feature = "sports")]
 pub mod sports;
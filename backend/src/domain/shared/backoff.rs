@@ -0,0 +1,135 @@
+//! ⏳ EXPONENTIAL BACKOFF AND RETRY-AFTER HELPERS
+//!
+//! This module centralizes retry delay math: an exponential `Backoff` iterator with a cap
+//! and optional jitter, and a parser for the HTTP `Retry-After` header in both its
+//! delay-seconds and HTTP-date forms.
+
+use std::time::{Duration, SystemTime};
+
+/// Infinite iterator of exponentially growing delays: `base * 2^n`, capped at `max`.
+///
+/// With `jitter` in `0.0..=1.0`, each delay is scaled by a random factor in
+/// `[1 - jitter, 1]` so concurrent clients do not retry in lockstep.
+/// Use `.take(n)` to bound the number of retries.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    jitter: f64,
+    attempt: u32,
+}
+
+impl Backoff {
+    /// Creates a backoff starting at `base`, never exceeding `max`.
+    pub fn new(base: Duration, max: Duration, jitter: f64) -> Self {
+        Backoff {
+            base,
+            max,
+            jitter: jitter.clamp(0.0, 1.0),
+            attempt: 0,
+        }
+    }
+
+    /// Largest delay this backoff will ever yield.
+    pub fn max_delay(&self) -> Duration {
+        self.max
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let factor = 2u32.saturating_pow(self.attempt);
+        let delay = self.base.saturating_mul(factor).min(self.max);
+        self.attempt = self.attempt.saturating_add(1);
+
+        if self.jitter == 0.0 {
+            return Some(delay);
+        }
+        let scale = 1.0 - self.jitter * fastrand::f64();
+        Some(delay.mul_f64(scale))
+    }
+}
+
+/// Parses a `Retry-After` header value into the delay to wait from `now`.
+///
+/// Accepts both delay-seconds (`"120"`) and an HTTP-date
+/// (`"Wed, 21 Oct 2015 07:28:00 GMT"`). Dates in the past yield a zero delay.
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()
+        .map(|date| date.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_double_until_the_cap() {
+        let delays: Vec<_> =
+            Backoff::new(Duration::from_millis(100), Duration::from_millis(500), 0.0)
+                .take(5)
+                .collect();
+
+        assert_eq!(
+            delays,
+            [100, 200, 400, 500, 500]
+                .map(Duration::from_millis)
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn jitter_scales_delays_within_bounds() {
+        let backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(1), 0.5);
+        let unjittered = Backoff::new(Duration::from_millis(100), Duration::from_secs(1), 0.0);
+
+        for (delay, full) in backoff.zip(unjittered).take(20) {
+            assert!(delay <= full, "{:?} exceeds {:?}", delay, full);
+            assert!(delay >= full / 2, "{:?} below half of {:?}", delay, full);
+        }
+    }
+
+    #[test]
+    fn jitter_is_clamped() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(1), 3.0);
+        assert!(backoff.next().unwrap() <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds() {
+        let now = SystemTime::now();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_accepts_http_dates() {
+        let now = httpdate::parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").unwrap();
+
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:30:00 GMT", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn retry_after_rejects_garbage() {
+        assert_eq!(parse_retry_after("soon", SystemTime::now()), None);
+        assert_eq!(parse_retry_after("-5", SystemTime::now()), None);
+    }
+}
@@ -0,0 +1,6 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct ErrorResponse {
+    pub message: String,
+}
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::domain::sports::sports::Leagues;
+use crate::domain::sports::sports::{Fixture, Leagues};
 
 #[derive(Serialize)]
 pub struct GetAllLeaguesResponse {
@@ -8,6 +8,11 @@ pub struct GetAllLeaguesResponse {
 }
 
 #[derive(Serialize)]
-pub struct ErrorResponse {
-    pub message: String,
+pub struct GetLeagueResponse {
+    pub league: Leagues,
+}
+
+#[derive(Serialize)]
+pub struct GetFixturesResponse {
+    pub fixtures: Vec<Fixture>,
 }
@@ -1,3 +1,4 @@
 pub mod dtos;
 pub mod services;
+#[allow(clippy::module_inception)]
 pub mod sports;
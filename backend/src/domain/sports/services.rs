@@ -1,53 +1,762 @@
 use crate::{
+    domain::shared::dtos::ErrorResponse,
     domain::sports::{
-        dtos::{ErrorResponse, GetAllLeaguesResponse},
-        sports::{Leagues, LeaguesApiResponse, API_AUTH_HEADER, API_BASE_URL, API_KEY},
+        dtos::{GetAllLeaguesResponse, GetFixturesResponse, GetLeagueResponse},
+        sports::{
+            Fixture, FixturesApiResponse, LeagueApiResponse, Leagues, LeaguesApiResponse,
+            API_AUTH_HEADER, API_KEY,
+        },
+    },
+    infrastructure::{
+        config::CONFIG,
+        web::{
+            cache::{last_known_cache, response_cache, ttl_for, CacheKey, CachedValue},
+            circuit_breaker::CircuitBreaker,
+            http_client::{send_request, RequestError},
+        },
     },
-    infrastructure::web::{cache::CACHE, http_client::send_request},
 };
-use axum::http::StatusCode;
+use axum::extract::{rejection::PathRejection, Path};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
+use chrono::{Days, NaiveDate, Utc};
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
 use reqwest::Method;
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use std::time::Duration;
 
-static INDEX_LEAGUES: u8 = 1;
-
-pub async fn get_leagues(
-) -> Result<(StatusCode, Json<GetAllLeaguesResponse>), (StatusCode, Json<ErrorResponse>)> {
-    match CACHE.get(&INDEX_LEAGUES) {
-        Some(leagues_str) => {
-            let leagues: Vec<Leagues> = serde_json::from_str(&leagues_str).unwrap_or(vec![]);
-            match leagues.len() > 0 {
-                false => match get_leagues_from_api().await {
-                    Ok(leagues) => {
-                        CACHE.insert(INDEX_LEAGUES, serde_json::to_string(&leagues).unwrap());
-                        Ok((StatusCode::OK, Json(GetAllLeaguesResponse { leagues })))
-                    }
-                    Err(e) => Err((
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse { message: e }),
-                    )),
-                },
-                true => Ok((StatusCode::OK, Json(GetAllLeaguesResponse { leagues }))),
+/// Client used by the handlers, calling the API at `SPORTS_API_BASE_URL`.
+static SPORTS_API: Lazy<SportsApi> = Lazy::new(|| {
+    SportsApi::new(
+        CONFIG.sports_api_base_url.clone(),
+        CircuitBreaker::new(
+            "sports_api",
+            CONFIG.sports_breaker_failure_threshold,
+            Duration::from_secs(CONFIG.sports_breaker_cooldown_sec),
+        ),
+    )
+});
+
+type ErrorReply = (StatusCode, Json<ErrorResponse>);
+
+/// Client for the sportmonks API rooted at a configurable base URL.
+///
+/// Every call goes through `breaker`. Responses are kept in `cache` for their TTL and in
+/// `last_known` beyond it, so they can still be served while the API is unavailable.
+pub struct SportsApi {
+    base_url: String,
+    breaker: CircuitBreaker,
+    cache: Cache<CacheKey, CachedValue>,
+    last_known: Cache<CacheKey, CachedValue>,
+}
+
+/// Returns all leagues, serving them from the cache when possible.
+///
+/// The response carries `ETag` and `Last-Modified` validators taken from the cache entry,
+/// and a `304 Not Modified` is returned when the client's conditional headers match it.
+pub async fn get_leagues(headers: HeaderMap) -> Result<Response, ErrorReply> {
+    SPORTS_API.leagues(&headers).await
+}
+
+/// Returns a single league by id, checking the cache before asking the sports API.
+///
+/// Responds `404` when the API does not know the league; that answer is cached
+/// for `NOT_FOUND_TTL`.
+/// A non-numeric id gets a JSON `400`.
+pub async fn get_league_by_id(
+    path: Result<Path<u32>, PathRejection>,
+    headers: HeaderMap,
+) -> Result<Response, ErrorReply> {
+    let Path(id) = path.map_err(bad_path)?;
+    SPORTS_API.league(id, &headers).await
+}
+
+/// Returns the upcoming fixtures of a league, cached for a short time since they change often.
+/// A non-numeric league id gets a JSON `400`.
+pub async fn get_fixtures(
+    path: Result<Path<u32>, PathRejection>,
+    headers: HeaderMap,
+) -> Result<Response, ErrorReply> {
+    let Path(league_id) = path.map_err(bad_path)?;
+    SPORTS_API.fixtures(league_id, &headers).await
+}
+
+/// Reports a malformed id in the path as a JSON `400`, like other error replies.
+fn bad_path(rejection: PathRejection) -> ErrorReply {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse {
+            message: rejection.body_text(),
+        }),
+    )
+}
+
+impl SportsApi {
+    /// Creates a client for the API at `base_url`, without a trailing slash,
+    /// guarded by `breaker` and starting with empty caches.
+    pub fn new(base_url: String, breaker: CircuitBreaker) -> Self {
+        SportsApi {
+            base_url,
+            breaker,
+            cache: response_cache(),
+            last_known: last_known_cache(),
+        }
+    }
+
+    async fn leagues(&self, headers: &HeaderMap) -> Result<Response, ErrorReply> {
+        let (cached, leagues, cache_control) = self
+            .load_cached(
+                CacheKey::Leagues,
+                |leagues: &Vec<Leagues>| !leagues.is_empty(),
+                || self.fetch_leagues(),
+            )
+            .await?;
+
+        Ok(conditional_response(
+            headers,
+            &cached,
+            cache_control,
+            GetAllLeaguesResponse { leagues },
+        ))
+    }
+
+    async fn league(&self, id: u32, headers: &HeaderMap) -> Result<Response, ErrorReply> {
+        let not_found = || {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    message: format!("League {} not found", id),
+                }),
+            )
+        };
+
+        if self.cache.contains_key(&CacheKey::UnknownLeague(id)) {
+            return Err(not_found());
+        }
+
+        let (cached, league, cache_control) = self
+            .load_cached(
+                CacheKey::League(id),
+                |league: &Option<Leagues>| league.is_some(),
+                || self.fetch_league(id),
+            )
+            .await?;
+
+        match league {
+            Some(league) => Ok(conditional_response(
+                headers,
+                &cached,
+                cache_control,
+                GetLeagueResponse { league },
+            )),
+            None => {
+                self.cache.insert(CacheKey::UnknownLeague(id), cached);
+                Err(not_found())
             }
         }
-        None => match get_leagues_from_api().await {
-            Ok(leagues) => {
-                CACHE.insert(INDEX_LEAGUES, serde_json::to_string(&leagues).unwrap());
-                Ok((StatusCode::OK, Json(GetAllLeaguesResponse { leagues })))
+    }
+
+    async fn fixtures(&self, league_id: u32, headers: &HeaderMap) -> Result<Response, ErrorReply> {
+        let (cached, fixtures, cache_control) = self
+            .load_cached(
+                CacheKey::Fixtures(league_id),
+                |_: &Vec<Fixture>| true,
+                || self.fetch_fixtures(league_id, Utc::now().date_naive()),
+            )
+            .await?;
+
+        Ok(conditional_response(
+            headers,
+            &cached,
+            cache_control,
+            GetFixturesResponse { fixtures },
+        ))
+    }
+}
+
+/// Why a guarded sports API fetch produced no value.
+enum FetchError {
+    /// The circuit breaker is open; the API was not called.
+    Unavailable,
+    /// The API call failed.
+    Failed(String),
+}
+
+impl SportsApi {
+    /// Loads a value from the cache, or fetches it from the sports API through the breaker.
+    ///
+    /// Only values accepted by `is_usable` are cached. While the breaker is open the
+    /// last known value for `key` is served, or `503` when there is none. Other API
+    /// failures are logged and answered with a generic `502`.
+    ///
+    /// Also returns the `Cache-Control` to send: the entry's remaining TTL for cached
+    /// values, and `no-cache` for uncached or last known values.
+    async fn load_cached<T, F, Fut>(
+        &self,
+        key: CacheKey,
+        is_usable: impl Fn(&T) -> bool,
+        fetch: F,
+    ) -> Result<(CachedValue, T, HeaderValue), ErrorReply>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let parse = |cached: CachedValue| {
+            serde_json::from_str::<T>(&cached.json)
+                .ok()
+                .filter(|value| is_usable(value))
+                .map(|value| (cached, value))
+        };
+
+        let no_cache = HeaderValue::from_static("no-cache");
+
+        if let Some((cached, value)) = self.cache.get(&key).and_then(parse) {
+            let cache_control = cached.cache_control(ttl_for(&key));
+            return Ok((cached, value, cache_control));
+        }
+
+        match self.fetch_and_cache(key, &is_usable, fetch).await {
+            Ok((cached, value)) => {
+                let cache_control = match is_usable(&value) {
+                    true => cached.cache_control(ttl_for(&key)),
+                    false => no_cache,
+                };
+                Ok((cached, value, cache_control))
             }
-            Err(e) => Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse { message: e }),
-            )),
-        },
+            Err(FetchError::Unavailable) => self
+                .last_known
+                .get(&key)
+                .and_then(parse)
+                .map(|(cached, value)| (cached, value, no_cache))
+                .ok_or_else(|| {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        Json(ErrorResponse {
+                            message: "Sports API temporarily unavailable".to_string(),
+                        }),
+                    )
+                }),
+            Err(FetchError::Failed(e)) => {
+                println!("❌ Sports API request for {:?} failed: {}", key, e);
+                Err((
+                    StatusCode::BAD_GATEWAY,
+                    Json(ErrorResponse {
+                        message: "Sports API request failed".to_string(),
+                    }),
+                ))
+            }
+        }
+    }
+
+    /// Calls the sports API through the circuit breaker and caches usable results
+    /// in both `cache` and `last_known`, keeping the previous validators when the
+    /// content did not change.
+    async fn fetch_and_cache<T, F, Fut>(
+        &self,
+        key: CacheKey,
+        is_usable: impl Fn(&T) -> bool,
+        fetch: F,
+    ) -> Result<(CachedValue, T), FetchError>
+    where
+        T: Serialize,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        if !self.breaker.try_acquire() {
+            return Err(FetchError::Unavailable);
+        }
+
+        match fetch().await {
+            Ok(value) => {
+                self.breaker.record_success();
+                let json = serde_json::to_string(&value).unwrap();
+                let cached = match self.cache.get(&key).or_else(|| self.last_known.get(&key)) {
+                    Some(previous) => previous.renewed(json),
+                    None => CachedValue::new(json),
+                };
+                if is_usable(&value) {
+                    self.cache.insert(key, cached.clone());
+                    self.last_known.insert(key, cached.clone());
+                }
+                Ok((cached, value))
+            }
+            Err(e) => {
+                self.breaker.record_failure();
+                Err(FetchError::Failed(e))
+            }
+        }
+    }
+}
+
+/// Proactively refreshes the leagues cache every `interval`, starting immediately,
+/// so user requests find it warm. Refreshes are skipped while the breaker is open.
+pub async fn refresh_leagues_periodically(interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        SPORTS_API.refresh_leagues().await;
+    }
+}
+
+impl SportsApi {
+    /// Fetches the leagues into the cache once, logging instead of failing.
+    async fn refresh_leagues(&self) {
+        match self
+            .fetch_and_cache(
+                CacheKey::Leagues,
+                |leagues: &Vec<Leagues>| !leagues.is_empty(),
+                || self.fetch_leagues(),
+            )
+            .await
+        {
+            Ok(_) => {}
+            Err(FetchError::Unavailable) => {
+                println!("⏭️ Skipping leagues refresh: sports API circuit is open")
+            }
+            Err(FetchError::Failed(e)) => println!("❌ Leagues refresh failed: {}", e),
+        }
+    }
+}
+
+/// Builds a `200` with the cache validators and `cache_control`, or a `304` if the
+/// client already has this entry.
+fn conditional_response<T: Serialize>(
+    headers: &HeaderMap,
+    cached: &CachedValue,
+    cache_control: HeaderValue,
+    body: T,
+) -> Response {
+    let mut response_headers = cached.validator_headers();
+    response_headers.insert(header::CACHE_CONTROL, cache_control);
+
+    if cached.is_not_modified(headers) {
+        return (StatusCode::NOT_MODIFIED, response_headers).into_response();
+    }
+
+    (StatusCode::OK, response_headers, Json(body)).into_response()
+}
+
+/// Upper bound on pages followed for one fixtures listing, guarding against a looping API.
+const MAX_FIXTURE_PAGES: u32 = 50;
+
+/// Days ahead of today covered by the fixtures listing.
+const UPCOMING_FIXTURES_DAYS: u64 = 30;
+
+impl SportsApi {
+    async fn fetch_leagues(&self) -> Result<Vec<Leagues>, String> {
+        let url = format!("{}/leagues{}{}", self.base_url, API_AUTH_HEADER, API_KEY);
+        match send_request::<(), LeaguesApiResponse>(&url, Method::GET, None, None, None).await {
+            Ok(Some(leagues_response)) => Ok(leagues_response.data),
+            Ok(None) => Ok(vec![]),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    async fn fetch_league(&self, id: u32) -> Result<Option<Leagues>, String> {
+        let url = format!(
+            "{}/leagues/{}{}{}",
+            self.base_url, id, API_AUTH_HEADER, API_KEY
+        );
+        match send_request::<(), LeagueApiResponse>(&url, Method::GET, None, None, None).await {
+            Ok(Some(league_response)) => Ok(league_response.data),
+            Ok(None) => Ok(None),
+            Err(RequestError {
+                status: Some(StatusCode::NOT_FOUND),
+                ..
+            }) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Fetches every page of a league's fixtures starting within `UPCOMING_FIXTURES_DAYS`
+    /// of `today`, and aggregates them into one list.
+    async fn fetch_fixtures(
+        &self,
+        league_id: u32,
+        today: NaiveDate,
+    ) -> Result<Vec<Fixture>, String> {
+        let until = today
+            .checked_add_days(Days::new(UPCOMING_FIXTURES_DAYS))
+            .unwrap_or(today);
+        let mut fixtures = vec![];
+
+        // Pages are counted locally so an API misreporting `current_page` cannot loop us
+        for page in 1..=MAX_FIXTURE_PAGES {
+            let url = format!(
+                "{}/fixtures{}{}&filter[league_id]={}&filter[starts_between]={},{}&page={}",
+                self.base_url, API_AUTH_HEADER, API_KEY, league_id, today, until, page
+            );
+            let response =
+                send_request::<(), FixturesApiResponse>(&url, Method::GET, None, None, None)
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+            let Some(response) = response else {
+                break;
+            };
+            fixtures.extend(response.data);
+
+            match response.meta {
+                Some(meta) if page < meta.last_page => {
+                    if page == MAX_FIXTURE_PAGES {
+                        println!(
+                            "⚠️ Fixtures of league {} truncated to {} of {} pages",
+                            league_id, page, meta.last_page
+                        );
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        Ok(fixtures)
     }
 }
 
-async fn get_leagues_from_api() -> Result<Vec<Leagues>, String> {
-    let url = format!("{}/leagues{}{}", API_BASE_URL, API_AUTH_HEADER, API_KEY);
-    match send_request::<(), LeaguesApiResponse>(&url, Method::GET, None, None, None).await {
-        Ok(Some(leagues_response)) => Ok(leagues_response.data),
-        Ok(None) => Ok(vec![]),
-        Err(e) => Err(e),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::web::cache::{DEFAULT_TTL, FIXTURES_TTL};
+    use crate::infrastructure::web::circuit_breaker::BreakerState;
+    use crate::test_support::{body_json, spawn_mock_server};
+    use axum::{body::to_bytes, extract::Query, routing::get, Router};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+    use std::time::SystemTime;
+
+    fn test_api(base_url: String) -> SportsApi {
+        SportsApi::new(
+            base_url,
+            CircuitBreaker::new("test", 2, Duration::from_secs(60)),
+        )
+    }
+
+    fn league_json(id: u32) -> Value {
+        json!({
+            "resource": "leagues",
+            "id": id,
+            "season_id": 1,
+            "country_id": 2,
+            "name": format!("League {}", id),
+            "code": format!("L{}", id),
+            "image_path": "",
+            "type": "league",
+            "updated_at": "2026-10-01T00:00:00.000000Z",
+        })
+    }
+
+    /// Ids at or above this are unknown to the mock leagues API.
+    const UNKNOWN_LEAGUE_ID: u32 = 1000;
+
+    /// Mock leagues API listing leagues 1 and 2 on `/leagues` and knowing every id
+    /// below `UNKNOWN_LEAGUE_ID` on `/leagues/:id`, counting the calls it receives.
+    async fn leagues_api() -> (String, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let list_counter = hits.clone();
+        let router = Router::new()
+            .route(
+                "/leagues",
+                get(move || {
+                    list_counter.fetch_add(1, Ordering::SeqCst);
+                    async { Json(json!({ "data": [league_json(1), league_json(2)] })) }
+                }),
+            )
+            .route(
+                "/leagues/:id",
+                get(move |Path(id): Path<u32>| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        match id < UNKNOWN_LEAGUE_ID {
+                            true => Json(json!({ "data": league_json(id) })).into_response(),
+                            false => StatusCode::NOT_FOUND.into_response(),
+                        }
+                    }
+                }),
+            );
+        (spawn_mock_server(router).await, hits)
+    }
+
+    /// Mock API failing every request with a `500` that echoes the request URI.
+    async fn failing_api() -> String {
+        let router = Router::new().fallback(|uri: axum::http::Uri| async move {
+            (StatusCode::INTERNAL_SERVER_ERROR, uri.to_string())
+        });
+        spawn_mock_server(router).await
+    }
+
+    #[tokio::test]
+    async fn league_miss_populates_the_cache() {
+        let (base_url, hits) = leagues_api().await;
+        let api = test_api(base_url);
+
+        let response = api.league(5, &HeaderMap::new()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["league"]["id"], 5);
+        assert!(api.cache.contains_key(&CacheKey::League(5)));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn league_hit_skips_the_api() {
+        let (base_url, hits) = leagues_api().await;
+        let api = test_api(base_url);
+        api.league(5, &HeaderMap::new()).await.unwrap();
+
+        let response = api.league(5, &HeaderMap::new()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["league"]["id"], 5);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn unknown_league_is_not_found_and_briefly_cached() {
+        let (base_url, hits) = leagues_api().await;
+        let api = test_api(base_url);
+
+        for _ in 0..2 {
+            let (status, Json(error)) = api
+                .league(UNKNOWN_LEAGUE_ID, &HeaderMap::new())
+                .await
+                .unwrap_err();
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert_eq!(error.message, "League 1000 not found");
+        }
+
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert!(!api.cache.contains_key(&CacheKey::League(UNKNOWN_LEAGUE_ID)));
+    }
+
+    #[tokio::test]
+    async fn returned_etag_yields_not_modified() {
+        let (base_url, _) = leagues_api().await;
+        let api = test_api(base_url);
+        let first = api.league(5, &HeaderMap::new()).await.unwrap();
+        let etag = first.headers()[header::ETAG].clone();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.clone());
+        let second = api.league(5, &headers).await.unwrap();
+
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag);
+        assert!(max_age(&second) > 0);
+        let body = to_bytes(second.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn refresh_populates_the_cache_without_requests() {
+        let (base_url, hits) = leagues_api().await;
+        let api = test_api(base_url);
+
+        api.refresh_leagues().await;
+
+        assert!(api.cache.contains_key(&CacheKey::Leagues));
+        let response = api.leagues(&HeaderMap::new()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["leagues"][0]["id"], 1);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn unchanged_refresh_keeps_the_etag() {
+        let (base_url, hits) = leagues_api().await;
+        let api = test_api(base_url);
+
+        api.refresh_leagues().await;
+        let first = api.cache.get(&CacheKey::Leagues).unwrap();
+        api.refresh_leagues().await;
+        let second = api.cache.get(&CacheKey::Leagues).unwrap();
+
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(second.etag, first.etag);
+        assert_eq!(second.last_modified, first.last_modified);
+    }
+
+    /// Reads the `max-age` of a publicly cacheable response.
+    fn max_age(response: &Response) -> u64 {
+        let cache_control = response.headers()[header::CACHE_CONTROL].to_str().unwrap();
+        cache_control
+            .strip_prefix("public, max-age=")
+            .unwrap_or_else(|| panic!("not publicly cacheable: {}", cache_control))
+            .parse()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetched_league_is_cacheable_for_its_full_ttl() {
+        let (base_url, _) = leagues_api().await;
+        let api = test_api(base_url);
+
+        let response = api.league(5, &HeaderMap::new()).await.unwrap();
+
+        let ttl = DEFAULT_TTL.as_secs();
+        assert!((ttl - 1..=ttl).contains(&max_age(&response)));
+    }
+
+    #[tokio::test]
+    async fn cached_league_advertises_its_remaining_ttl() {
+        let (base_url, _) = leagues_api().await;
+        let api = test_api(base_url);
+        let aged = CachedValue {
+            cached_at: SystemTime::now() - Duration::from_secs(100),
+            ..CachedValue::new(json!(Some(league_json(5))).to_string())
+        };
+        api.cache.insert(CacheKey::League(5), aged);
+
+        let response = api.league(5, &HeaderMap::new()).await.unwrap();
+
+        let ttl = DEFAULT_TTL.as_secs() - 100;
+        assert!((ttl - 1..=ttl).contains(&max_age(&response)));
+    }
+
+    #[tokio::test]
+    async fn fixtures_are_cacheable_for_the_fixtures_ttl() {
+        let (base_url, _) = paginated_fixtures_api(1).await;
+        let api = test_api(base_url);
+
+        let response = api.fixtures(7, &HeaderMap::new()).await.unwrap();
+
+        let ttl = FIXTURES_TTL.as_secs();
+        assert!((ttl - 1..=ttl).contains(&max_age(&response)));
+    }
+
+    #[tokio::test]
+    async fn upstream_failures_return_a_generic_bad_gateway() {
+        let api = test_api(failing_api().await);
+
+        let (status, Json(error)) = api.league(1, &HeaderMap::new()).await.unwrap_err();
+
+        assert_eq!(status, StatusCode::BAD_GATEWAY);
+        assert_eq!(error.message, "Sports API request failed");
+    }
+
+    #[tokio::test]
+    async fn last_known_value_is_served_while_the_breaker_is_open() {
+        let (base_url, hits) = leagues_api().await;
+        let api = test_api(base_url);
+        api.league(1, &HeaderMap::new()).await.unwrap();
+
+        api.cache.invalidate_all();
+        api.breaker.record_failure();
+        api.breaker.record_failure();
+        assert_eq!(api.breaker.state(), BreakerState::Open);
+
+        let response = api.league(1, &HeaderMap::new()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-cache");
+        assert_eq!(body_json(response).await["league"]["id"], 1);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        let (status, _) = api.league(2, &HeaderMap::new()).await.unwrap_err();
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    fn fixture_json(id: u32, league_id: u32) -> Value {
+        json!({
+            "resource": "fixtures",
+            "id": id,
+            "league_id": league_id,
+            "season_id": 1,
+            "round": null,
+            "localteam_id": 10,
+            "visitorteam_id": 20,
+            "starting_at": "2026-10-20T10:00:00.000000Z",
+            "type": "T20",
+            "live": false,
+            "status": "NS",
+            "venue_id": null,
+            "winner_team_id": null,
+        })
+    }
+
+    /// Mock `/fixtures` endpoint serving `last_page` pages with one fixture each,
+    /// recording the query of every call.
+    async fn paginated_fixtures_api(
+        last_page: u32,
+    ) -> (String, Arc<Mutex<Vec<HashMap<String, String>>>>) {
+        fixtures_api(last_page, None).await
+    }
+
+    /// Like `paginated_fixtures_api`, but reporting `stuck_page` as `current_page`
+    /// on every answer when set, as an API ignoring `page=` would.
+    async fn fixtures_api(
+        last_page: u32,
+        stuck_page: Option<u32>,
+    ) -> (String, Arc<Mutex<Vec<HashMap<String, String>>>>) {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let recorded = calls.clone();
+        let router = Router::new().route(
+            "/fixtures",
+            get(move |Query(query): Query<HashMap<String, String>>| {
+                let page: u32 = query["page"].parse().unwrap();
+                let league_id: u32 = query["filter[league_id]"].parse().unwrap();
+                recorded.lock().unwrap().push(query);
+                async move {
+                    Json(json!({
+                        "data": [fixture_json(page, league_id)],
+                        "meta": {
+                            "current_page": stuck_page.unwrap_or(page),
+                            "last_page": last_page,
+                        },
+                    }))
+                }
+            }),
+        );
+        (spawn_mock_server(router).await, calls)
+    }
+
+    #[tokio::test]
+    async fn fixtures_aggregate_every_page_of_the_upcoming_window() {
+        let (base_url, calls) = paginated_fixtures_api(3).await;
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+        let fixtures = test_api(base_url).fetch_fixtures(7, today).await.unwrap();
+
+        let ids: Vec<u32> = fixtures.iter().map(|fixture| fixture.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert!(fixtures.iter().all(|fixture| fixture.league_id == 7));
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0]["filter[starts_between]"], "2026-10-17,2026-11-16");
+    }
+
+    #[tokio::test]
+    async fn fixtures_terminate_when_the_api_repeats_the_first_page() {
+        let (base_url, calls) = fixtures_api(3, Some(1)).await;
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+        let fixtures = test_api(base_url).fetch_fixtures(7, today).await.unwrap();
+
+        let calls = calls.lock().unwrap();
+        assert!(calls.len() <= MAX_FIXTURE_PAGES as usize);
+        assert_eq!(calls.len(), 3);
+        assert_eq!(fixtures.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn fixtures_stop_at_the_page_limit() {
+        let (base_url, calls) = paginated_fixtures_api(MAX_FIXTURE_PAGES + 10).await;
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+        let fixtures = test_api(base_url).fetch_fixtures(7, today).await.unwrap();
+
+        assert_eq!(fixtures.len(), MAX_FIXTURE_PAGES as usize);
+        assert_eq!(calls.lock().unwrap().len(), MAX_FIXTURE_PAGES as usize);
     }
 }
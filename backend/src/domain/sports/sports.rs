@@ -9,6 +9,11 @@ pub struct LeaguesApiResponse {
     pub data: Vec<Leagues>
 }
 
+#[derive(Deserialize, Debug)]
+pub struct LeagueApiResponse {
+    pub data: Option<Leagues>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Leagues {
     pub resource: String,
@@ -23,3 +28,35 @@ pub struct Leagues {
     pub league_type: String,
     pub updated_at: String,
 }
+
+#[derive(Deserialize, Debug)]
+pub struct FixturesApiResponse {
+    pub data: Vec<Fixture>,
+    pub meta: Option<PaginationMeta>,
+}
+
+/// Laravel-style pagination block returned by the sports API on list endpoints.
+#[derive(Deserialize, Debug)]
+pub struct PaginationMeta {
+    pub current_page: u32,
+    pub last_page: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Fixture {
+    pub resource: String,
+    pub id: u32,
+    pub league_id: u32,
+    pub season_id: u32,
+    pub round: Option<String>,
+    pub localteam_id: u32,
+    pub visitorteam_id: u32,
+    pub starting_at: Option<String>,
+
+    #[serde(rename = "type")]
+    pub fixture_type: Option<String>,
+    pub live: bool,
+    pub status: Option<String>,
+    pub venue_id: Option<u32>,
+    pub winner_team_id: Option<u32>,
+}
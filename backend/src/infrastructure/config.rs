@@ -0,0 +1,214 @@
+//! ⚙️ RUNTIME CONFIGURATION LOADED FROM THE ENVIRONMENT
+//!
+//! This module reads optional settings from environment variables once, at first use,
+//! and exposes them through the global `CONFIG`. Every setting has a safe default so
+//! the server starts without any configuration.
+
+use axum::http::{HeaderValue, Uri};
+use ipnet::IpNet;
+use once_cell::sync::Lazy;
+
+#[cfg(feature = "sports")]
+use crate::{domain::sports::sports::API_BASE_URL, infrastructure::web::cache::DEFAULT_TTL};
+
+/// Settings read from the environment.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Proxies (`TRUSTED_PROXIES`, comma-separated CIDRs or IPs) whose forwarded headers are trusted.
+    pub trusted_proxies: Vec<IpNet>,
+    /// URL that `/` redirects to (`ROOT_REDIRECT`); when unset `/` returns service info.
+    pub root_redirect: Option<HeaderValue>,
+    /// Seconds browsers may cache CORS preflight results (`CORS_MAX_AGE_SEC`, default 86400).
+    pub cors_max_age_sec: u64,
+    /// Consecutive sports API failures before its circuit breaker opens
+    /// (`SPORTS_BREAKER_FAILURE_THRESHOLD`, default 5).
+    #[cfg(feature = "sports")]
+    pub sports_breaker_failure_threshold: u32,
+    /// Seconds the sports API breaker stays open before a trial call
+    /// (`SPORTS_BREAKER_COOLDOWN_SEC`, default 30).
+    #[cfg(feature = "sports")]
+    pub sports_breaker_cooldown_sec: u64,
+    /// Whether `/path/` is routed like `/path` (`NORMALIZE_TRAILING_SLASH`, default false).
+    pub normalize_trailing_slash: bool,
+    /// Base URL of the sportmonks API (`SPORTS_API_BASE_URL`), overridable for
+    /// staging or local mocks.
+    #[cfg(feature = "sports")]
+    pub sports_api_base_url: String,
+    /// Seconds between background leagues cache refreshes
+    /// (`SPORTS_REFRESH_INTERVAL_SEC`, default 300; `0` disables the job).
+    /// Values not below the 600-second cache TTL are replaced by half of it, so the
+    /// leagues entry never expires between refreshes.
+    #[cfg(feature = "sports")]
+    pub sports_refresh_interval_sec: u64,
+    /// Tokio worker threads (`TOKIO_WORKER_THREADS`, default: number of CPU cores).
+    pub tokio_worker_threads: usize,
+    /// Upper bound on Tokio blocking threads (`TOKIO_MAX_BLOCKING_THREADS`, default 512).
+    pub tokio_max_blocking_threads: usize,
+}
+
+impl Config {
+    /// Builds the configuration from the current environment variables.
+    pub fn from_env() -> Self {
+        Config {
+            trusted_proxies: parse_trusted_proxies(
+                &std::env::var("TRUSTED_PROXIES").unwrap_or_default(),
+            ),
+            root_redirect: std::env::var("ROOT_REDIRECT")
+                .ok()
+                .and_then(|value| parse_root_redirect(&value)),
+            cors_max_age_sec: parse_env("CORS_MAX_AGE_SEC", 86400),
+            #[cfg(feature = "sports")]
+            sports_breaker_failure_threshold: parse_env("SPORTS_BREAKER_FAILURE_THRESHOLD", 5),
+            #[cfg(feature = "sports")]
+            sports_breaker_cooldown_sec: parse_env("SPORTS_BREAKER_COOLDOWN_SEC", 30),
+            normalize_trailing_slash: parse_env("NORMALIZE_TRAILING_SLASH", false),
+            #[cfg(feature = "sports")]
+            sports_api_base_url: std::env::var("SPORTS_API_BASE_URL")
+                .ok()
+                .map(|value| value.trim().trim_end_matches('/').to_string())
+                .filter(|value| !value.is_empty())
+                .unwrap_or_else(|| API_BASE_URL.to_string()),
+            #[cfg(feature = "sports")]
+            sports_refresh_interval_sec: cap_refresh_interval(parse_env(
+                "SPORTS_REFRESH_INTERVAL_SEC",
+                DEFAULT_TTL.as_secs() / 2,
+            )),
+            tokio_worker_threads: parse_env("TOKIO_WORKER_THREADS", default_worker_threads())
+                .max(1),
+            tokio_max_blocking_threads: parse_env("TOKIO_MAX_BLOCKING_THREADS", 512).max(1),
+        }
+    }
+}
+
+/// Number of CPU cores available to the process, or 1 if it cannot be determined.
+fn default_worker_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Reads and parses an environment variable, falling back to `default` when unset or invalid.
+fn parse_env<T: std::str::FromStr>(name: &str, default: T) -> T {
+    match std::env::var(name) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+            println!("⚠️ Ignoring invalid {} value '{}'", name, value);
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
+/// Keeps the leagues refresh interval below the cache TTL, warning about and halving
+/// the TTL for values that would let the cached entry expire between runs.
+#[cfg(feature = "sports")]
+fn cap_refresh_interval(interval_sec: u64) -> u64 {
+    let ttl_sec = DEFAULT_TTL.as_secs();
+    match interval_sec < ttl_sec {
+        true => interval_sec,
+        false => {
+            println!(
+                "⚠️ Ignoring SPORTS_REFRESH_INTERVAL_SEC value '{}': must be below the {}-second cache TTL, using {}",
+                interval_sec,
+                ttl_sec,
+                ttl_sec / 2
+            );
+            ttl_sec / 2
+        }
+    }
+}
+
+/// Validates a `ROOT_REDIRECT` target as a URI usable in a `Location` header,
+/// ignoring blank values and warning about invalid ones.
+fn parse_root_redirect(value: &str) -> Option<HeaderValue> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    match (value.parse::<Uri>(), HeaderValue::from_str(value)) {
+        (Ok(_), Ok(location)) => Some(location),
+        _ => {
+            println!("⚠️ Ignoring invalid ROOT_REDIRECT value '{}'", value);
+            None
+        }
+    }
+}
+
+/// Parses a comma-separated list of CIDRs or bare IPs, skipping invalid entries.
+fn parse_trusted_proxies(value: &str) -> Vec<IpNet> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            entry
+                .parse::<IpNet>()
+                .or_else(|_| entry.parse::<std::net::IpAddr>().map(IpNet::from))
+                .map_err(|e| {
+                    println!(
+                        "⚠️ Ignoring invalid TRUSTED_PROXIES entry '{}': {}",
+                        entry, e
+                    )
+                })
+                .ok()
+        })
+        .collect()
+}
+
+pub static CONFIG: Lazy<Config> = Lazy::new(Config::from_env);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "sports")]
+    #[test]
+    fn refresh_interval_is_kept_below_the_cache_ttl() {
+        let ttl_sec = DEFAULT_TTL.as_secs();
+
+        assert_eq!(cap_refresh_interval(0), 0);
+        assert_eq!(cap_refresh_interval(ttl_sec - 1), ttl_sec - 1);
+        assert_eq!(cap_refresh_interval(ttl_sec), ttl_sec / 2);
+        assert_eq!(cap_refresh_interval(900), ttl_sec / 2);
+    }
+
+    #[test]
+    fn root_redirect_accepts_urls_and_paths() {
+        assert_eq!(
+            parse_root_redirect(" https://example.com/app "),
+            Some(HeaderValue::from_static("https://example.com/app"))
+        );
+        assert_eq!(
+            parse_root_redirect("/docs"),
+            Some(HeaderValue::from_static("/docs"))
+        );
+    }
+
+    #[test]
+    fn root_redirect_ignores_blank_and_invalid_values() {
+        assert_eq!(parse_root_redirect("   "), None);
+        assert_eq!(parse_root_redirect("https://exa mple.com"), None);
+        assert_eq!(parse_root_redirect("https://example.com/\u{7f}"), None);
+    }
+
+    #[test]
+    fn trusted_proxies_accept_cidrs_and_bare_ips() {
+        let proxies = parse_trusted_proxies("10.0.0.0/8, 192.168.1.10 ,::1,");
+
+        assert_eq!(
+            proxies,
+            vec![
+                "10.0.0.0/8".parse::<IpNet>().unwrap(),
+                "192.168.1.10/32".parse().unwrap(),
+                "::1/128".parse().unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn trusted_proxies_skip_invalid_entries() {
+        let proxies = parse_trusted_proxies("not-an-ip, 10.0.0.0/33, 172.16.0.0/12");
+
+        assert_eq!(proxies, vec!["172.16.0.0/12".parse::<IpNet>().unwrap()]);
+    }
+}
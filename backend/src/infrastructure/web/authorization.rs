@@ -4,10 +4,12 @@
 //! It also provides a CORS layer configuration for HTTP request handling.
 
 use axum::http::{header, Method};
+use std::time::Duration;
 use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer};
 
 /// Creates a CORS layer configured with allowed origins, methods, headers, and credentials.
-pub fn cors_layer() -> CorsLayer {
+/// It answers preflight `OPTIONS` requests for every route, letting browsers cache them for `max_age`.
+pub fn cors_layer(max_age: Duration) -> CorsLayer {
     CorsLayer::new()
         .allow_origin(AllowOrigin::any())
         .allow_methods(AllowMethods::list([
@@ -29,5 +31,5 @@ pub fn cors_layer() -> CorsLayer {
             header::AUTHORIZATION,
             "X-Total-Count".parse().unwrap(),
         ])
-        .max_age(std::time::Duration::from_secs(86400))
+        .max_age(max_age)
 }
@@ -1,10 +1,369 @@
-use moka::sync::Cache;
-use once_cell::sync::Lazy;
-use std::time::Duration;
-
-pub static CACHE: Lazy<Cache<u8, String>> = Lazy::new(|| {
-    Cache::builder()
-        .time_to_live(Duration::from_secs(10 * 60))
-        .time_to_idle(Duration::from_secs(2 * 60))
-        .build()
-});
+//! 🗃️ IN-MEMORY RESPONSE CACHE WITH HTTP VALIDATORS
+//!
+//! This module builds the moka caches used by the sports services.
+//! Each entry keeps the serialized JSON together with the validators (`ETag`
+//! and `Last-Modified`) computed when its content was first cached, so handlers can
+//! answer conditional requests with `304 Not Modified`.
+
+use axum::http::{header, HeaderMap, HeaderValue};
+#[cfg(feature = "sports")]
+use moka::{sync::Cache, Expiry};
+#[cfg(feature = "sports")]
+use std::time::Instant;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Typed keys for the entries stored in the response caches.
+#[cfg(feature = "sports")]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum CacheKey {
+    /// The full list of leagues. Kept warm by the refresh job, so exempt from `IDLE_TIMEOUT`.
+    Leagues,
+    /// A single league by its sports API id.
+    League(u32),
+    /// The fixtures of a league, by league id. Expires after `FIXTURES_TTL`.
+    Fixtures(u32),
+    /// A league id the sports API answered `404` for. Expires after `NOT_FOUND_TTL`.
+    UnknownLeague(u32),
+}
+
+/// Default lifetime of cache entries, such as leagues.
+#[cfg(feature = "sports")]
+pub const DEFAULT_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Fixtures change often (live scores, rescheduling), so they are kept for less time.
+#[cfg(feature = "sports")]
+pub const FIXTURES_TTL: Duration = Duration::from_secs(60);
+
+/// Unknown ids are remembered briefly, sparing the API repeated lookups without
+/// hiding a league for long once it is published.
+#[cfg(feature = "sports")]
+pub const NOT_FOUND_TTL: Duration = Duration::from_secs(60);
+
+/// Entries not read for this long are evicted before their TTL, except `CacheKey::Leagues`.
+#[cfg(feature = "sports")]
+pub const IDLE_TIMEOUT: Duration = Duration::from_secs(2 * 60);
+
+/// Lifetime of the entry stored under `key`.
+#[cfg(feature = "sports")]
+pub fn ttl_for(key: &CacheKey) -> Duration {
+    match key {
+        CacheKey::Fixtures(_) => FIXTURES_TTL,
+        CacheKey::UnknownLeague(_) => NOT_FOUND_TTL,
+        CacheKey::Leagues | CacheKey::League(_) => DEFAULT_TTL,
+    }
+}
+
+/// Per-entry expiry policy: each key lives for its `ttl_for`, and reads push expiry
+/// to `IDLE_TIMEOUT` from now without ever extending past that TTL.
+#[cfg(feature = "sports")]
+struct CacheExpiry;
+
+#[cfg(feature = "sports")]
+impl Expiry<CacheKey, CachedValue> for CacheExpiry {
+    fn expire_after_create(
+        &self,
+        key: &CacheKey,
+        _value: &CachedValue,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(ttl_for(key))
+    }
+
+    fn expire_after_read(
+        &self,
+        key: &CacheKey,
+        value: &CachedValue,
+        _read_at: Instant,
+        duration_until_expiry: Option<Duration>,
+        _last_modified_at: Instant,
+    ) -> Option<Duration> {
+        match key {
+            CacheKey::Leagues => duration_until_expiry,
+            _ => Some(IDLE_TIMEOUT.min(value.remaining_ttl(ttl_for(key)))),
+        }
+    }
+
+    fn expire_after_update(
+        &self,
+        key: &CacheKey,
+        _value: &CachedValue,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(ttl_for(key))
+    }
+}
+
+/// A cached JSON payload and the HTTP validators derived from when its content changed.
+#[derive(Clone, Debug)]
+pub struct CachedValue {
+    pub json: String,
+    pub etag: String,
+    pub last_modified: SystemTime,
+    /// When this value was last (re)inserted, which starts its TTL.
+    pub cached_at: SystemTime,
+}
+
+impl CachedValue {
+    /// Wraps a serialized payload, stamping it with the current time.
+    pub fn new(json: String) -> Self {
+        let now = SystemTime::now();
+        let nanos = now
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        CachedValue {
+            json,
+            etag: format!("\"{:x}\"", nanos),
+            last_modified: now,
+            cached_at: now,
+        }
+    }
+
+    /// Wraps a freshly fetched payload, keeping this value's validators when the
+    /// content is unchanged so clients' `ETag`s stay valid across refreshes.
+    pub fn renewed(&self, json: String) -> Self {
+        match json == self.json {
+            true => CachedValue {
+                cached_at: SystemTime::now(),
+                ..self.clone()
+            },
+            false => CachedValue::new(json),
+        }
+    }
+
+    /// Time left before this value outlives `ttl`.
+    pub fn remaining_ttl(&self, ttl: Duration) -> Duration {
+        let age = self.cached_at.elapsed().unwrap_or_default();
+        ttl.saturating_sub(age)
+    }
+
+    /// Returns `true` when the request's `If-None-Match` or `If-Modified-Since`
+    /// headers show the client already holds this version.
+    ///
+    /// `If-None-Match` takes precedence over `If-Modified-Since`, as required by RFC 9110.
+    pub fn is_not_modified(&self, headers: &HeaderMap) -> bool {
+        if let Some(if_none_match) = headers.get(header::IF_NONE_MATCH) {
+            return if_none_match
+                .to_str()
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == self.etag)
+                })
+                .unwrap_or(false);
+        }
+
+        headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| httpdate::parse_http_date(value).ok())
+            .map(|since| to_http_precision(self.last_modified) <= since)
+            .unwrap_or(false)
+    }
+
+    /// `Cache-Control` letting proxies and browsers keep this value no longer than
+    /// the server-side cache does, given the entry's `ttl`.
+    pub fn cache_control(&self, ttl: Duration) -> HeaderValue {
+        HeaderValue::from_str(&format!(
+            "public, max-age={}",
+            self.remaining_ttl(ttl).as_secs()
+        ))
+        .expect("Cache-Control value is valid ASCII")
+    }
+
+    /// Builds the `ETag` and `Last-Modified` headers describing this entry.
+    pub fn validator_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Ok(etag) = HeaderValue::from_str(&self.etag) {
+            headers.insert(header::ETAG, etag);
+        }
+        if let Ok(last_modified) =
+            HeaderValue::from_str(&httpdate::fmt_http_date(self.last_modified))
+        {
+            headers.insert(header::LAST_MODIFIED, last_modified);
+        }
+        headers
+    }
+}
+
+/// Truncates a timestamp to whole seconds, the resolution of HTTP dates.
+fn to_http_precision(time: SystemTime) -> SystemTime {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+/// Builds a cache of fresh responses, expiring entries after their TTL.
+#[cfg(feature = "sports")]
+pub fn response_cache() -> Cache<CacheKey, CachedValue> {
+    Cache::builder().expire_after(CacheExpiry).build()
+}
+
+/// Builds a cache of the last successfully fetched value per key, kept beyond the
+/// response cache expiry so it can be served while an upstream is unavailable.
+#[cfg(feature = "sports")]
+pub fn last_known_cache() -> Cache<CacheKey, CachedValue> {
+    Cache::builder().max_capacity(1_000).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached_value() -> CachedValue {
+        CachedValue {
+            json: "{}".to_string(),
+            etag: "\"abc\"".to_string(),
+            last_modified: UNIX_EPOCH + Duration::from_millis(1_700_000_000_500),
+            cached_at: SystemTime::now(),
+        }
+    }
+
+    fn request_headers(pairs: &[(header::HeaderName, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(name.clone(), HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn strong_etag_matches() {
+        let headers = request_headers(&[(header::IF_NONE_MATCH, "\"abc\"")]);
+        assert!(cached_value().is_not_modified(&headers));
+    }
+
+    #[test]
+    fn weak_etag_matches() {
+        let headers = request_headers(&[(header::IF_NONE_MATCH, "W/\"abc\"")]);
+        assert!(cached_value().is_not_modified(&headers));
+    }
+
+    #[test]
+    fn wildcard_matches() {
+        let headers = request_headers(&[(header::IF_NONE_MATCH, "*")]);
+        assert!(cached_value().is_not_modified(&headers));
+    }
+
+    #[test]
+    fn etag_list_matches_any_member() {
+        let matching = request_headers(&[(header::IF_NONE_MATCH, "\"old\", W/\"abc\" ,\"other\"")]);
+        let stale = request_headers(&[(header::IF_NONE_MATCH, "\"old\", \"other\"")]);
+
+        assert!(cached_value().is_not_modified(&matching));
+        assert!(!cached_value().is_not_modified(&stale));
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence_over_if_modified_since() {
+        let headers = request_headers(&[
+            (header::IF_NONE_MATCH, "\"old\""),
+            (header::IF_MODIFIED_SINCE, "Tue, 14 Nov 2023 22:13:20 GMT"),
+        ]);
+
+        assert!(!cached_value().is_not_modified(&headers));
+    }
+
+    #[test]
+    fn if_modified_since_compares_whole_seconds() {
+        // `last_modified` is 22:13:20.5; the client only saw the whole second
+        let same_second =
+            request_headers(&[(header::IF_MODIFIED_SINCE, "Tue, 14 Nov 2023 22:13:20 GMT")]);
+        let earlier =
+            request_headers(&[(header::IF_MODIFIED_SINCE, "Tue, 14 Nov 2023 22:13:19 GMT")]);
+
+        assert!(cached_value().is_not_modified(&same_second));
+        assert!(!cached_value().is_not_modified(&earlier));
+    }
+
+    #[test]
+    fn invalid_if_modified_since_is_ignored() {
+        let headers = request_headers(&[(header::IF_MODIFIED_SINCE, "yesterday")]);
+        assert!(!cached_value().is_not_modified(&headers));
+    }
+
+    #[test]
+    fn validator_headers_describe_the_entry() {
+        let headers = cached_value().validator_headers();
+
+        assert_eq!(headers[header::ETAG], "\"abc\"");
+        assert_eq!(
+            headers[header::LAST_MODIFIED],
+            "Tue, 14 Nov 2023 22:13:20 GMT"
+        );
+    }
+
+    #[test]
+    fn renewed_keeps_validators_of_unchanged_content() {
+        let value = cached_value();
+
+        let same = value.renewed("{}".to_string());
+        let changed = value.renewed("[]".to_string());
+
+        assert_eq!(same.etag, value.etag);
+        assert_eq!(same.last_modified, value.last_modified);
+        assert!(same.cached_at >= value.cached_at);
+        assert_ne!(changed.etag, value.etag);
+    }
+
+    #[cfg(feature = "sports")]
+    #[test]
+    fn reads_extend_expiry_by_the_idle_timeout_within_the_ttl() {
+        let fresh = cached_value();
+        let old = CachedValue {
+            cached_at: SystemTime::now() - (DEFAULT_TTL - Duration::from_secs(30)),
+            ..cached_value()
+        };
+        let read = |key: &CacheKey, value: &CachedValue| {
+            CacheExpiry.expire_after_read(
+                key,
+                value,
+                Instant::now(),
+                Some(Duration::from_secs(500)),
+                Instant::now(),
+            )
+        };
+
+        assert_eq!(read(&CacheKey::League(1), &fresh), Some(IDLE_TIMEOUT));
+        assert!(read(&CacheKey::League(1), &old).unwrap() <= Duration::from_secs(30));
+    }
+
+    #[cfg(feature = "sports")]
+    #[test]
+    fn leagues_list_is_exempt_from_the_idle_timeout() {
+        let expiry = CacheExpiry.expire_after_read(
+            &CacheKey::Leagues,
+            &cached_value(),
+            Instant::now(),
+            Some(Duration::from_secs(500)),
+            Instant::now(),
+        );
+
+        assert_eq!(expiry, Some(Duration::from_secs(500)));
+    }
+
+    #[test]
+    fn cache_control_counts_down_the_remaining_ttl() {
+        let ttl = Duration::from_secs(600);
+        let aged = CachedValue {
+            cached_at: SystemTime::now() - Duration::from_secs(100),
+            ..cached_value()
+        };
+        let expired = CachedValue {
+            cached_at: SystemTime::now() - ttl * 2,
+            ..cached_value()
+        };
+
+        let max_age = aged.cache_control(ttl);
+        assert!(
+            max_age == "public, max-age=500" || max_age == "public, max-age=499",
+            "{:?}",
+            max_age
+        );
+        assert_eq!(expired.cache_control(ttl), "public, max-age=0");
+    }
+}
@@ -0,0 +1,199 @@
+//! 🔌 CIRCUIT BREAKER FOR UNRELIABLE UPSTREAM SERVICES
+//!
+//! This module provides a small closed/open/half-open circuit breaker. After a number of
+//! consecutive failures the breaker opens and callers short-circuit for a cooldown period
+//! instead of hitting the failing service. Once the cooldown elapses a single trial call
+//! is let through: success closes the breaker, failure opens it again.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Observable state of a breaker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { since: Instant },
+}
+
+/// Tracks consecutive failures of an upstream and decides whether calls may proceed.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    name: &'static str,
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker that opens after `failure_threshold` consecutive failures
+    /// and stays open for `cooldown`.
+    pub fn new(name: &'static str, failure_threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreaker {
+            name,
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    /// Returns the current state of the breaker.
+    pub fn state(&self) -> BreakerState {
+        match *self.lock() {
+            State::Closed { .. } => BreakerState::Closed,
+            State::Open { .. } => BreakerState::Open,
+            State::HalfOpen { .. } => BreakerState::HalfOpen,
+        }
+    }
+
+    /// Returns `true` if a call may be made now.
+    ///
+    /// While open, this moves to half-open once the cooldown has elapsed and admits one
+    /// trial call. A trial that never reports back is replaced after another cooldown.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.lock();
+        match *state {
+            State::Closed { .. } => true,
+            State::Open { since } | State::HalfOpen { since } => {
+                if since.elapsed() >= self.cooldown {
+                    *state = State::HalfOpen {
+                        since: Instant::now(),
+                    };
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Records a successful call, closing the breaker.
+    pub fn record_success(&self) {
+        let mut state = self.lock();
+        if !matches!(*state, State::Closed { .. }) {
+            println!("✅ Circuit breaker '{}' closed", self.name);
+        }
+        *state = State::Closed { failures: 0 };
+    }
+
+    /// Records a failed call, opening the breaker when the threshold is reached
+    /// or when a half-open trial fails.
+    pub fn record_failure(&self) {
+        let mut state = self.lock();
+        let should_open = match *state {
+            State::Closed { failures } => {
+                let failures = failures + 1;
+                *state = State::Closed { failures };
+                failures >= self.failure_threshold
+            }
+            State::HalfOpen { .. } => true,
+            State::Open { .. } => false,
+        };
+
+        if should_open {
+            println!(
+                "⚠️ Circuit breaker '{}' opened for {:?}",
+                self.name, self.cooldown
+            );
+            *state = State::Open {
+                since: Instant::now(),
+            };
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COOLDOWN: Duration = Duration::from_millis(50);
+
+    fn open_breaker() -> CircuitBreaker {
+        let breaker = CircuitBreaker::new("test", 2, COOLDOWN);
+        breaker.record_failure();
+        breaker.record_failure();
+        breaker
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new("test", 2, COOLDOWN);
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+        assert!(breaker.try_acquire());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Open);
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn success_resets_the_failure_count() {
+        let breaker = CircuitBreaker::new("test", 2, COOLDOWN);
+
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+
+        assert_eq!(breaker.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn half_open_admits_a_single_trial() {
+        let breaker = open_breaker();
+        std::thread::sleep(COOLDOWN);
+
+        assert!(breaker.try_acquire());
+        assert_eq!(breaker.state(), BreakerState::HalfOpen);
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn successful_trial_closes_the_breaker() {
+        let breaker = open_breaker();
+        std::thread::sleep(COOLDOWN);
+
+        assert!(breaker.try_acquire());
+        breaker.record_success();
+
+        assert_eq!(breaker.state(), BreakerState::Closed);
+        assert!(breaker.try_acquire());
+    }
+
+    #[test]
+    fn failed_trial_reopens_the_breaker() {
+        let breaker = open_breaker();
+        std::thread::sleep(COOLDOWN);
+
+        assert!(breaker.try_acquire());
+        breaker.record_failure();
+
+        assert_eq!(breaker.state(), BreakerState::Open);
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn abandoned_trial_is_replaced_after_another_cooldown() {
+        let breaker = open_breaker();
+        std::thread::sleep(COOLDOWN);
+        assert!(breaker.try_acquire());
+
+        std::thread::sleep(COOLDOWN);
+        assert!(breaker.try_acquire());
+        assert_eq!(breaker.state(), BreakerState::HalfOpen);
+    }
+}
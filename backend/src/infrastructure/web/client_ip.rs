@@ -0,0 +1,129 @@
+//! 🧭 CLIENT IP RESOLUTION BEHIND REVERSE PROXIES
+//!
+//! This module determines the real client address of a request. Forwarding headers
+//! (`X-Forwarded-For`, `X-Real-IP`) are only honored when the TCP peer is one of the
+//! configured `TRUSTED_PROXIES`; otherwise they could be spoofed by any client.
+
+use axum::extract::ConnectInfo;
+use axum::http::{request::Parts, HeaderMap};
+use ipnet::IpNet;
+use std::net::{IpAddr, SocketAddr};
+
+use crate::infrastructure::config::CONFIG;
+
+/// Returns the client IP for a request, using forwarded headers only from trusted proxies.
+pub fn client_ip(parts: &Parts, connect_info: &ConnectInfo<SocketAddr>) -> IpAddr {
+    resolve_client_ip(&parts.headers, connect_info.0.ip(), &CONFIG.trusted_proxies)
+}
+
+/// Resolves the client IP from the peer address and headers against a proxy list.
+///
+/// `X-Forwarded-For` is walked right to left, skipping trusted hops, so the first
+/// untrusted address is taken as the client. `X-Real-IP` is used when no
+/// `X-Forwarded-For` is present. Any unparseable value falls back to the peer.
+pub fn resolve_client_ip(headers: &HeaderMap, peer: IpAddr, trusted_proxies: &[IpNet]) -> IpAddr {
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+
+    if !is_trusted(&peer) {
+        return peer;
+    }
+
+    if let Some(forwarded_for) = headers
+        .get("X-Forwarded-For")
+        .and_then(|value| value.to_str().ok())
+    {
+        let hops: Option<Vec<IpAddr>> = forwarded_for
+            .split(',')
+            .map(|hop| hop.trim().parse::<IpAddr>().ok())
+            .collect();
+
+        return match hops {
+            Some(hops) => hops
+                .iter()
+                .rev()
+                .find(|hop| !is_trusted(hop))
+                .or_else(|| hops.first())
+                .copied()
+                .unwrap_or(peer),
+            None => peer,
+        };
+    }
+
+    headers
+        .get("X-Real-IP")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<IpAddr>().ok())
+        .unwrap_or(peer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn proxies() -> Vec<IpNet> {
+        vec!["10.0.0.0/8".parse().unwrap()]
+    }
+
+    #[test]
+    fn untrusted_peer_ignores_spoofed_headers() {
+        let headers = headers(&[("x-forwarded-for", "1.2.3.4"), ("x-real-ip", "5.6.7.8")]);
+
+        assert_eq!(
+            resolve_client_ip(&headers, ip("203.0.113.9"), &proxies()),
+            ip("203.0.113.9")
+        );
+    }
+
+    #[test]
+    fn trusted_peer_walks_forwarded_for_right_to_left() {
+        let headers = headers(&[("x-forwarded-for", "1.2.3.4, 198.51.100.7, 10.0.0.2")]);
+
+        assert_eq!(
+            resolve_client_ip(&headers, ip("10.0.0.1"), &proxies()),
+            ip("198.51.100.7")
+        );
+    }
+
+    #[test]
+    fn all_trusted_chain_yields_the_first_hop() {
+        let headers = headers(&[("x-forwarded-for", "10.1.1.1, 10.0.0.2")]);
+
+        assert_eq!(
+            resolve_client_ip(&headers, ip("10.0.0.1"), &proxies()),
+            ip("10.1.1.1")
+        );
+    }
+
+    #[test]
+    fn unparseable_hop_falls_back_to_the_peer() {
+        let headers = headers(&[("x-forwarded-for", "1.2.3.4, unknown")]);
+
+        assert_eq!(
+            resolve_client_ip(&headers, ip("10.0.0.1"), &proxies()),
+            ip("10.0.0.1")
+        );
+    }
+
+    #[test]
+    fn real_ip_is_used_without_forwarded_for() {
+        let headers = headers(&[("x-real-ip", " 198.51.100.7 ")]);
+
+        assert_eq!(
+            resolve_client_ip(&headers, ip("10.0.0.1"), &proxies()),
+            ip("198.51.100.7")
+        );
+    }
+}
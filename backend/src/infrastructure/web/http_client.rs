@@ -3,12 +3,39 @@
 //! This module provides a generic async HTTP client function to send requests with optional headers and JSON bodies.
 //! It handles request timeouts, response status codes, and logs errors with tracing.
 
-use reqwest::{Method, Client, header::HeaderMap, StatusCode};
+use reqwest::{Method, Client, header::{HeaderMap, RETRY_AFTER}, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use core::fmt;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
+use crate::domain::shared::backoff::{parse_retry_after, Backoff};
+
+/// Retries attempted for idempotent requests after a transient failure.
+const MAX_RETRIES: u32 = 2;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+const RETRY_JITTER: f64 = 0.5;
+
+/// Error returned by `send_request`, keeping the upstream status when one was received.
+#[derive(Debug)]
+pub struct RequestError {
+    /// HTTP status returned by the remote server, or `None` for transport/parsing failures.
+    pub status: Option<StatusCode>,
+    pub message: String,
+}
+
+impl RequestError {
+    fn new(status: Option<StatusCode>, message: String) -> Self {
+        RequestError { status, message }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
 
 /// Sends an HTTP request asynchronously with optional headers and JSON body.
 /// Returns the deserialized response data if available.
@@ -23,17 +50,24 @@ use std::time::Duration;
 /// # Returns
 /// - `Ok(Some(T))` if response contains JSON body successfully deserialized to `T`.
 /// - `Ok(None)` if response has no content or body cannot be deserialized.
-/// - `Err(String)` if request fails or returns error status.
+/// - `Err(RequestError)` if request fails or returns error status, with the status when known.
+///
+/// # Retries
+/// Idempotent requests (GET, HEAD, PUT, DELETE, OPTIONS) are retried up to `MAX_RETRIES` times
+/// on connection errors, timeouts, `429` and `502`–`504`, waiting with exponential backoff
+/// and honoring `Retry-After` when it does not exceed the backoff cap.
 ///
 /// # Logging
-/// Errors and failures are logged at error level with detailed messages.
+/// Errors and failures are logged at error level with detailed messages. URLs are logged
+/// and reported without their query string, and error response bodies are dropped,
+/// so API tokens never leak.
 pub async fn send_request<T, R>(
     url: &str,
     method: Method,
     headers: Option<&HeaderMap>,
     body: Option<&T>,
     timeout_sec: Option<u64>,
-) -> Result<Option<R>, String>
+) -> Result<Option<R>, RequestError>
 where
     T: Serialize + fmt::Debug,
     R: DeserializeOwned + fmt::Debug,
@@ -43,26 +77,71 @@ where
         .build()
         .map_err(|e| {
             println!("❌ Error creating HTTP client: {}", e);
-            format!("Error creating HTTP client: {}", e)
+            RequestError::new(None, format!("Error creating HTTP client: {}", e))
         })?;
 
-    let mut request_builder = client.request(method, url);
+    let is_idempotent = matches!(
+        method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    );
+    let mut backoff = Backoff::new(RETRY_BASE_DELAY, RETRY_MAX_DELAY, RETRY_JITTER);
+    let mut retries_left = if is_idempotent { MAX_RETRIES } else { 0 };
 
-    // Add headers if present
-    if let Some(headers_map) = headers {
-        request_builder = request_builder.headers(headers_map.clone());
-    }
+    let response = loop {
+        let mut request_builder = client.request(method.clone(), url);
 
-    // Add JSON body if present
-    if let Some(body_data) = body {
-        request_builder = request_builder.json(body_data);
-    }
+        // Add headers if present
+        if let Some(headers_map) = headers {
+            request_builder = request_builder.headers(headers_map.clone());
+        }
 
-    let response = match request_builder.send().await {
+        // Add JSON body if present
+        if let Some(body_data) = body {
+            request_builder = request_builder.json(body_data);
+        }
+
+        let result = request_builder.send().await;
+
+        // Decide whether this attempt is worth retrying, and after how long
+        let retry_delay = match &result {
+            _ if retries_left == 0 => None,
+            Err(e) if e.is_timeout() || e.is_connect() => backoff.next(),
+            Ok(resp) if is_retryable_status(resp.status()) => {
+                let delay = backoff.next();
+                match resp
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| parse_retry_after(value, SystemTime::now()))
+                {
+                    // Don't wait longer than the backoff cap; give up instead
+                    Some(retry_after) if retry_after > backoff.max_delay() => None,
+                    Some(retry_after) => delay.map(|delay| delay.max(retry_after)),
+                    None => delay,
+                }
+            }
+            _ => None,
+        };
+
+        match retry_delay {
+            Some(delay) => {
+                retries_left -= 1;
+                println!("🔁 Retrying request to {} in {:?}", redact_url(url), delay);
+                tokio::time::sleep(delay).await;
+            }
+            None => break result,
+        }
+    };
+
+    let response = match response {
         Ok(resp) => resp,
         Err(e) => {
-            println!("❌ Error sending request: {}", e);
-            return Err(format!("❌ Error sending request: {}", e));
+            let e = e.without_url();
+            println!("❌ Error sending request to {}: {}", redact_url(url), e);
+            return Err(RequestError::new(
+                e.status(),
+                format!("❌ Error sending request to {}: {}", redact_url(url), e),
+            ));
         }
     };
 
@@ -72,8 +151,12 @@ where
             match response.json::<R>().await {
                 Ok(data) => Ok(Some(data)),
                 Err(e) => {
+                    let e = e.without_url();
                     println!("❌ Error deserializing response: {}", e);
-                    Err(format!("❌ Error deserializing response: {}", e))
+                    Err(RequestError::new(
+                        None,
+                        format!("❌ Error deserializing response: {}", e),
+                    ))
                 },
             }
         }
@@ -82,12 +165,147 @@ where
             Ok(None)
         },
         status => {
-            let text = response.text().await.unwrap_or_default();
-            println!("❌ Request failed with status {}: {}", status, text);
-            Err(format!(
-                "Request failed with status {}: {}",
-                status, text
+            // The body is not logged: upstreams may echo the request URL, token included
+            println!("❌ Request to {} failed with status {}", redact_url(url), status);
+            Err(RequestError::new(
+                Some(status),
+                format!("Request failed with status {}", status),
             ))
         }
     }
 }
+
+/// Strips the query string from `url`, which may carry credentials such as API tokens,
+/// so it can be logged or reported safely.
+fn redact_url(url: &str) -> &str {
+    url.split_once('?').map_or(url, |(path, _)| path)
+}
+
+/// Statuses that signal a transient upstream condition worth retrying.
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::spawn_mock_server;
+    use axum::{http::header, routing::get, Router};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Mock API answering `503` with the given `Retry-After`, counting the calls it receives.
+    async fn unavailable_api(retry_after: &'static str) -> (String, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/data",
+            get(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        [(header::RETRY_AFTER, retry_after)],
+                    )
+                }
+            }),
+        );
+        (spawn_mock_server(router).await, hits)
+    }
+
+    #[tokio::test]
+    async fn gives_up_when_retry_after_exceeds_the_cap() {
+        let (base_url, hits) = unavailable_api("3600").await;
+
+        let result = send_request::<(), serde_json::Value>(
+            &format!("{}/data?api_token=secret", base_url),
+            Method::GET,
+            None,
+            None,
+            None,
+        )
+        .await;
+
+        let error = result.unwrap_err();
+        assert_eq!(error.status, Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_transient_statuses() {
+        let (base_url, hits) = unavailable_api("0").await;
+
+        let result = send_request::<(), serde_json::Value>(
+            &format!("{}/data", base_url),
+            Method::GET,
+            None,
+            None,
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 1 + MAX_RETRIES as usize);
+    }
+
+    #[tokio::test]
+    async fn errors_do_not_leak_the_query_string() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let error = send_request::<(), serde_json::Value>(
+            &format!("http://{}/data?api_token=secret", addr),
+            Method::POST,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(!error.message.contains("secret"), "{}", error.message);
+    }
+
+    #[tokio::test]
+    async fn error_statuses_do_not_leak_an_echoed_url() {
+        let router = Router::new().fallback(|uri: axum::http::Uri| async move {
+            (StatusCode::INTERNAL_SERVER_ERROR, uri.to_string())
+        });
+        let base_url = spawn_mock_server(router).await;
+
+        let error = send_request::<(), serde_json::Value>(
+            &format!("{}/data?api_token=secret", base_url),
+            Method::GET,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.status, Some(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!error.message.contains("api_token"), "{}", error.message);
+        assert!(!error.message.contains("secret"), "{}", error.message);
+    }
+
+    #[test]
+    fn redact_url_strips_the_query() {
+        assert_eq!(
+            redact_url("https://api.example.com/leagues?api_token=secret"),
+            "https://api.example.com/leagues"
+        );
+        assert_eq!(
+            redact_url("https://api.example.com/leagues"),
+            "https://api.example.com/leagues"
+        );
+    }
+}
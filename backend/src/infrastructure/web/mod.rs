@@ -5,5 +5,7 @@
 
 pub mod authorization;
 pub mod cache;
+pub mod circuit_breaker;
+pub mod client_ip;
 pub mod http_client;
 pub mod routes;
@@ -7,40 +7,283 @@
 //! Includes CORS support and HTTP metrics tracking middleware.
 
 use axum::{
-    http::StatusCode,
-    response::IntoResponse,
-    routing::{get, post},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
     Json, Router,
 };
 use serde_json::json;
+use std::time::Duration;
+use tower_http::normalize_path::NormalizePath;
 
+#[cfg(feature = "sports")]
+use crate::domain::sports::services::{get_fixtures, get_league_by_id, get_leagues};
 use crate::{
-    domain::sports::services::get_leagues, infrastructure::web::authorization::cors_layer,
+    domain::shared::dtos::ErrorResponse,
+    infrastructure::{
+        config::{Config, CONFIG},
+        web::authorization::cors_layer,
+    },
 };
 
-/// Basic health check or welcome endpoint returning a JSON message.
-async fn index() -> impl IntoResponse {
-    Json(json!({"message": "Hello, World!"}))
+/// Root endpoint. Redirects (`302`) to `ROOT_REDIRECT` when configured,
+/// otherwise returns the service name and version.
+async fn index(root_redirect: Option<HeaderValue>) -> Response {
+    match root_redirect {
+        Some(url) => (StatusCode::FOUND, [(header::LOCATION, url)]).into_response(),
+        None => Json(json!({
+            "service": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+        }))
+        .into_response(),
+    }
 }
 
-/// Handles preflight OPTIONS requests with appropriate CORS headers.
-async fn handle_options() -> impl IntoResponse {
+/// Informational endpoint reporting which build is deployed.
+/// Values are embedded at compile time by `build.rs`.
+async fn version() -> impl IntoResponse {
+    Json(json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "git_sha": env!("GIT_SHA"),
+        "build_time": env!("BUILD_TIME"),
+        "rust_version": env!("RUSTC_VERSION"),
+    }))
+}
+
+/// Fallback for unmatched paths, returning a JSON `404` like other error responses.
+async fn not_found() -> impl IntoResponse {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse {
+            message: "Not found".to_string(),
+        }),
+    )
+}
+
+/// Fallback for known paths requested with an unsupported method, returning a JSON `405`.
+async fn method_not_allowed() -> impl IntoResponse {
     (
-        StatusCode::NO_CONTENT,
-        [("Access-Control-Max-Age", "86400")],
+        StatusCode::METHOD_NOT_ALLOWED,
+        Json(ErrorResponse {
+            message: "Method not allowed".to_string(),
+        }),
     )
 }
 
 /// Publicly accessible routes that do not require authentication.
 /// Includes registration, login, password reset, email verification, and metrics.
-fn public_routes() -> Router {
+fn public_routes(config: &Config) -> Router {
+    let root_redirect = config.root_redirect.clone();
+    Router::new()
+        .route("/", get(move || index(root_redirect)))
+        .route("/version", get(version))
+}
+
+/// Sports data routes (leagues and fixtures), compiled only with the `sports` feature.
+/// Handlers set `Cache-Control` from the remaining lifetime of their cache entry.
+#[cfg(feature = "sports")]
+fn sports_routes() -> Router {
     Router::new()
-        .route("/", get(index).options(handle_options))
         .route("/get_leagues", get(get_leagues))
+        .route("/leagues/:id", get(get_league_by_id))
+        .route("/leagues/:id/fixtures", get(get_fixtures))
 }
 
 /// Aggregates all routes into a single router, applying
 /// middleware layers for metrics tracking and CORS globally.
+/// Preflight requests are answered by the CORS layer on every route, and unmatched
+/// paths or methods get JSON `404`/`405` responses.
+///
+/// With `NORMALIZE_TRAILING_SLASH` enabled, trailing slashes are trimmed before
+/// routing so `/path/` and `/path` resolve to the same handler.
 pub fn routes() -> Router {
-    Router::new().merge(public_routes()).layer(cors_layer())
+    routes_with(&CONFIG)
+}
+
+/// Builds the routes for the given configuration instead of the global `CONFIG`.
+pub fn routes_with(config: &Config) -> Router {
+    let router = Router::new().merge(public_routes(config));
+
+    #[cfg(feature = "sports")]
+    let router = router.merge(sports_routes());
+
+    let router = router
+        .method_not_allowed_fallback(method_not_allowed)
+        .fallback(not_found)
+        .layer(cors_layer(Duration::from_secs(config.cors_max_age_sec)));
+
+    match config.normalize_trailing_slash {
+        // Path rewriting must happen before routing, so the whole router is wrapped
+        true => Router::new().fallback_service(NormalizePath::trim_trailing_slash(router)),
+        false => router,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::body_json;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn get_request(uri: &str) -> Request<Body> {
+        Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn root_returns_service_info_by_default() {
+        let config = Config {
+            root_redirect: None,
+            ..Config::from_env()
+        };
+
+        let response = routes_with(&config)
+            .oneshot(get_request("/"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body_json(response).await;
+        assert_eq!(body["service"], env!("CARGO_PKG_NAME"));
+        assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    }
+
+    #[tokio::test]
+    async fn root_redirects_when_configured() {
+        let config = Config {
+            root_redirect: Some(HeaderValue::from_static("https://example.com/app")),
+            ..Config::from_env()
+        };
+
+        let response = routes_with(&config)
+            .oneshot(get_request("/"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FOUND);
+        assert_eq!(
+            response.headers()[header::LOCATION],
+            "https://example.com/app"
+        );
+    }
+
+    #[cfg(not(feature = "sports"))]
+    #[tokio::test]
+    async fn sports_routes_are_absent_without_the_feature() {
+        let response = crate::build_app()
+            .oneshot(get_request("/get_leagues"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn unknown_path_returns_json_not_found() {
+        let response = crate::build_app()
+            .oneshot(get_request("/no/such/path"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(body_json(response).await["message"], "Not found");
+    }
+
+    #[tokio::test]
+    async fn unsupported_method_returns_json_method_not_allowed() {
+        let request = Request::post("/version").body(Body::empty()).unwrap();
+
+        let response = crate::build_app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(body_json(response).await["message"], "Method not allowed");
+    }
+
+    #[tokio::test]
+    async fn preflight_is_answered_on_non_root_routes() {
+        let request = Request::options("/version")
+            .header(header::ORIGIN, "https://app.example.com")
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+            .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = crate::build_app().oneshot(request).await.unwrap();
+
+        assert!(response.status().is_success());
+        let headers = response.headers();
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+            .to_str()
+            .unwrap()
+            .contains("GET"));
+        assert!(headers[header::ACCESS_CONTROL_ALLOW_HEADERS]
+            .to_str()
+            .unwrap()
+            .contains("authorization"));
+        assert!(headers.contains_key(header::ACCESS_CONTROL_MAX_AGE));
+    }
+
+    #[tokio::test]
+    async fn trailing_slash_resolves_when_normalization_is_enabled() {
+        let config = Config {
+            normalize_trailing_slash: true,
+            ..Config::from_env()
+        };
+
+        for uri in ["/version", "/version/"] {
+            let response = routes_with(&config)
+                .oneshot(get_request(uri))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn trailing_slash_is_not_found_by_default() {
+        let config = Config {
+            normalize_trailing_slash: false,
+            ..Config::from_env()
+        };
+
+        let response = routes_with(&config)
+            .oneshot(get_request("/version/"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn version_reports_the_build() {
+        let response = crate::build_app()
+            .oneshot(get_request("/version"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body_json(response).await;
+        assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(body["git_sha"], env!("GIT_SHA"));
+        assert_eq!(body["build_time"], env!("BUILD_TIME"));
+        assert_eq!(body["rust_version"], env!("RUSTC_VERSION"));
+    }
+
+    #[cfg(feature = "sports")]
+    #[tokio::test]
+    async fn non_numeric_league_id_returns_json_bad_request() {
+        for uri in ["/leagues/abc", "/leagues/abc/fixtures"] {
+            let response = crate::build_app().oneshot(get_request(uri)).await.unwrap();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", uri);
+            let body = body_json(response).await;
+            assert!(
+                body["message"].as_str().unwrap().contains("abc"),
+                "{}",
+                body
+            );
+        }
+    }
 }
@@ -1,5 +1,7 @@
 pub mod domain;
 pub mod infrastructure;
+#[cfg(test)]
+mod test_support;
 
 use axum::Router;
 use infrastructure::web::routes;
@@ -9,11 +11,27 @@ pub fn build_app() -> Router {
     Router::new().merge(routes::routes())
 }
 
+/// Builds the multi-threaded Tokio runtime with the given thread counts,
+/// normally `TOKIO_WORKER_THREADS` and `TOKIO_MAX_BLOCKING_THREADS` from `CONFIG`.
+pub fn build_runtime(
+    worker_threads: usize,
+    max_blocking_threads: usize,
+) -> std::io::Result<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(worker_threads)
+        .max_blocking_threads(max_blocking_threads)
+        .enable_all()
+        .build()
+}
+
 /// Runs the Axum server, initializing logging, metrics, DB and binding to `127.0.0.1:8000`.
 pub async fn run_server() {
     use std::net::SocketAddr;
     let app = build_app();
 
+    #[cfg(feature = "sports")]
+    spawn_background_jobs();
+
     let addr = SocketAddr::from(([127, 0, 0, 1], 8000));
     let listener = tokio::net::TcpListener::bind(addr)
         .await
@@ -33,3 +51,28 @@ pub async fn run_server() {
         std::process::exit(1);
     });
 }
+
+/// Starts the periodic sports cache refresh unless disabled with an interval of `0`.
+#[cfg(feature = "sports")]
+fn spawn_background_jobs() {
+    use infrastructure::config::CONFIG;
+    use std::time::Duration;
+
+    if CONFIG.sports_refresh_interval_sec > 0 {
+        tokio::spawn(domain::sports::services::refresh_leagues_periodically(
+            Duration::from_secs(CONFIG.sports_refresh_interval_sec),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_uses_the_requested_worker_threads() {
+        let runtime = build_runtime(3, 8).unwrap();
+
+        assert_eq!(runtime.metrics().num_workers(), 3);
+    }
+}
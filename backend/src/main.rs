@@ -1,8 +1,15 @@
 
 
-use backend_server::run_server;
+use backend_server::{build_runtime, infrastructure::config::CONFIG, run_server};
 
-#[tokio::main]
-async fn main() {
-    run_server().await;
+fn main() {
+    let runtime = build_runtime(
+        CONFIG.tokio_worker_threads,
+        CONFIG.tokio_max_blocking_threads,
+    )
+    .unwrap_or_else(|e| {
+        println!("❌ Failed to build Tokio runtime: {}", e);
+        std::process::exit(1);
+    });
+    runtime.block_on(run_server());
 }
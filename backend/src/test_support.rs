@@ -0,0 +1,18 @@
+//! 🧪 HELPERS SHARED BY UNIT TESTS
+
+use axum::{body::to_bytes, response::Response, Router};
+
+/// Serves `router` on an ephemeral local port and returns its base URL,
+/// standing in for third-party APIs during tests.
+pub async fn spawn_mock_server(router: Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    format!("http://{}", addr)
+}
+
+/// Reads a response body and parses it as JSON.
+pub async fn body_json(response: Response) -> serde_json::Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}